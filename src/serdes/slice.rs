@@ -9,11 +9,13 @@ use core::{
 		Formatter,
 	},
 	marker::PhantomData,
+	ops::Deref,
 };
 
 use serde::{
 	de::{
 		Deserialize,
+		DeserializeSeed,
 		Deserializer,
 		Error,
 		MapAccess,
@@ -39,7 +41,11 @@ use crate::{
 use crate::{
 	index::BitIdx,
 	mem::bits_of,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 	ptr::{
 		AddressExt,
 		BitSpan,
@@ -49,10 +55,70 @@ use crate::{
 	store::BitStore,
 };
 
+/// A short, stable tag identifying a [`BitOrder`] on the wire, used in
+/// place of `any::type_name::<O>()`.
+///
+/// `type_name` is explicitly documented by the standard library as an
+/// unstable, best-effort debugging aid: its exact output can change across
+/// compiler versions, or even between builds of the same crate version, so
+/// an artifact encoded under one toolchain can fail the `order !=
+/// expected_order` check after a rename or rustc upgrade.
+///
+/// This lives alongside the `serdes` machinery rather than as a method on
+/// [`BitOrder`] itself, since it's purely a wire-format concern and
+/// `BitOrder` is used unconditionally, with or without `serde` support.
+/// The trait is private to this module and is implemented only for
+/// [`Lsb0`] and [`Msb0`], which override [`ORDER_TAG`] with the fixed,
+/// human-readable tags below. The serde impls in this module are bounded
+/// on `OrderTag`, so bit-slices, `BitBox`es and `BitVec`s with any other
+/// ordering do not implement `Serialize` or `Deserialize`.
+///
+/// [`ORDER_TAG`]: OrderTag::ORDER_TAG
+trait OrderTag: BitOrder + 'static {
+	/// The tag written to, and accepted from, the `order` field.
+	const ORDER_TAG: &'static str = any::type_name::<Self>();
+}
+
+impl OrderTag for Lsb0 {
+	const ORDER_TAG: &'static str = "Lsb0";
+}
+
+impl OrderTag for Msb0 {
+	const ORDER_TAG: &'static str = "Msb0";
+}
+
+fn order_tag<O>() -> &'static str
+where O: OrderTag {
+	O::ORDER_TAG
+}
+
+/// Builds the [`DeserializeSeed`] that decodes the `data` field, once
+/// `head` and `bits` are known (or, for a misordered self-describing map,
+/// are not).
+///
+/// Implementations that don't need the element count — the borrowed and
+/// copy-on-write byte paths, which have no per-element width to size
+/// against — simply ignore the arguments.
+trait BitDataSeed<'de, T>: DeserializeSeed<'de>
+where T: BitStore
+{
+	fn new(head: Option<BitIdx<T::Mem>>, bits: Option<usize>) -> Self;
+
+	/// The number of bits of backing storage that a decoded `data` value
+	/// actually provides.
+	///
+	/// `BitSeqVisitor::assemble` uses this to check `head`/`bits` against
+	/// the real decoded length before asking `func` to build a span out of
+	/// it, so that an out-of-range `bits` is reported as a descriptive
+	/// error. The span construction in `func` receives only the address of
+	/// `data`, not its length, and so cannot catch this itself.
+	fn bit_len(value: &Self::Value) -> usize;
+}
+
 impl<T, O> Serialize for BitSlice<T, O>
 where
 	T: BitStore,
-	O: BitOrder,
+	O: OrderTag,
 	T::Mem: Serialize,
 {
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
@@ -60,20 +126,107 @@ where
 		let head = self.as_bitspan().head();
 		let mut state = serializer.serialize_struct("BitSeq", FIELDS.len())?;
 
-		state.serialize_field("order", &any::type_name::<O>())?;
+		state.serialize_field("order", &order_tag::<O>())?;
 		state.serialize_field("head", &head)?;
 		state.serialize_field("bits", &(self.len() as u64))?;
+
+		#[cfg(feature = "alloc")]
+		{
+			let packed = pack_le_bytes(self.domain().into_iter());
+			state.serialize_field("data", &Bytes(&packed))?;
+		}
+		#[cfg(not(feature = "alloc"))]
 		state.serialize_field("data", &self.domain())?;
 
 		state.end()
 	}
 }
 
+/// Serializes a byte slice as a single length-prefixed blob via
+/// [`Serializer::serialize_bytes`], rather than the per-element sequence
+/// that the blanket `serde` impls for `[u8]`/`&[u8]` produce. This is the
+/// same technique `serde_bytes` uses to keep binary formats (bincode,
+/// CBOR, MessagePack) compact.
+#[cfg(feature = "alloc")]
+struct Bytes<'a>(&'a [u8]);
+
+#[cfg(feature = "alloc")]
+impl<'a> Serialize for Bytes<'a> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+/// Packs an iterator of backing-store elements into a little-endian byte
+/// buffer, one element's worth of bytes at a time.
+///
+/// For `M = u8` this degenerates to a plain byte-for-byte copy. For wider
+/// stores, each element is written out least-significant-byte-first,
+/// regardless of host endianness, so the encoding is portable.
+#[cfg(feature = "alloc")]
+fn pack_le_bytes<M>(elems: impl Iterator<Item = M>) -> Vec<u8> {
+	let width = core::mem::size_of::<M>();
+	let (lower, _) = elems.size_hint();
+	let mut out = Vec::with_capacity(lower * width);
+	for elem in elems {
+		// SAFETY: `elem` is a live, initialized `M`, so reading `width`
+		// bytes starting at its address is always in-bounds.
+		let bytes = unsafe {
+			core::slice::from_raw_parts(&elem as *const M as *const u8, width)
+		};
+		if cfg!(target_endian = "big") {
+			out.extend(bytes.iter().rev().copied());
+		}
+		else {
+			out.extend_from_slice(bytes);
+		}
+	}
+	out
+}
+
+/// Reverses [`pack_le_bytes`], splitting a little-endian byte buffer back
+/// into backing-store elements.
+///
+/// Errors if `bytes` is not an exact multiple of `size_of::<M>()`, since
+/// that indicates a truncated or corrupt `data` field.
+#[cfg(feature = "alloc")]
+fn unpack_le_bytes<M, E>(bytes: &[u8]) -> Result<Vec<M>, E>
+where E: Error {
+	let width = core::mem::size_of::<M>();
+	if bytes.len() % width != 0 {
+		return Err(E::invalid_length(
+			bytes.len(),
+			&"a byte length that is a multiple of the element width",
+		));
+	}
+
+	let mut out = Vec::with_capacity(bytes.len() / width);
+	for chunk in bytes.chunks_exact(width) {
+		let mut elem = core::mem::MaybeUninit::<M>::uninit();
+		// SAFETY: `elem` has room for exactly `width` bytes, and `chunk`
+		// supplies exactly that many.
+		unsafe {
+			let dst = elem.as_mut_ptr() as *mut u8;
+			if cfg!(target_endian = "big") {
+				for (idx, byte) in chunk.iter().rev().enumerate() {
+					dst.add(idx).write(*byte);
+				}
+			}
+			else {
+				core::ptr::copy_nonoverlapping(chunk.as_ptr(), dst, width);
+			}
+			out.push(elem.assume_init());
+		}
+	}
+	Ok(out)
+}
+
 #[cfg(feature = "alloc")]
 impl<T, O> Serialize for BitBox<T, O>
 where
 	T: BitStore,
-	O: BitOrder,
+	O: OrderTag,
 	BitSlice<T, O>: Serialize,
 {
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
@@ -86,7 +239,7 @@ where
 impl<T, O> Serialize for BitVec<T, O>
 where
 	T: BitStore,
-	O: BitOrder,
+	O: OrderTag,
 	BitSlice<T, O>: Serialize,
 {
 	fn serialize<S>(&self, serializer: S) -> super::Result<S>
@@ -96,14 +249,14 @@ where
 }
 
 impl<'de, O> Deserialize<'de> for &'de BitSlice<u8, O>
-where O: BitOrder
+where O: OrderTag
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
 		deserializer.deserialize_struct(
 			"BitSeq",
 			FIELDS,
-			BitSeqVisitor::<'de, u8, O, &'de [u8], Self, _>::new(
+			BitSeqVisitor::<'de, u8, O, BorrowedBytesSeed, Self, _>::new(
 				|data, head, bits| unsafe {
 					BitSpan::new(data.as_ptr().into_address(), head, bits)
 						.map(|span| BitSpan::into_bitslice_ref(span))
@@ -113,12 +266,189 @@ where O: BitOrder
 	}
 }
 
+/// Decodes the `data` field as a borrowed byte blob, erroring for any
+/// deserializer that cannot hand one back.
+struct BorrowedBytesSeed;
+
+impl<'de> DeserializeSeed<'de> for BorrowedBytesSeed {
+	type Value = &'de [u8];
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_bytes(self)
+	}
+}
+
+impl<'de> Visitor<'de> for BorrowedBytesSeed {
+	type Value = &'de [u8];
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a borrowed byte blob")
+	}
+
+	fn visit_borrowed_bytes<E>(self, bytes: &'de [u8]) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(bytes)
+	}
+}
+
+impl<'de> BitDataSeed<'de, u8> for BorrowedBytesSeed {
+	fn new(_head: Option<BitIdx<u8>>, _bits: Option<usize>) -> Self {
+		Self
+	}
+
+	fn bit_len(value: &Self::Value) -> usize {
+		value.len() * bits_of::<u8>()
+	}
+}
+
+/// An owned-or-borrowed `BitSlice<u8, O>`, produced by deserializing
+/// through [`CowBitSlice`]'s `Deserialize` impl rather than `&'de
+/// BitSlice<u8, O>`'s.
+///
+/// `Deserialize for &'de BitSlice<u8, O>` only succeeds when the format
+/// can hand back a borrowed `&'de [u8]`; formats that own their buffers
+/// (many `Read`-based decoders, decompressing transports) fail outright.
+/// `CowBitSlice` accepts either: it borrows zero-copy when the
+/// deserializer allows it, and falls back to an owned `BitVec` otherwise,
+/// mirroring the `Cow<[u8]>` pattern from `serde_bytes` so that one field
+/// works across both bincode-style and self-describing decoders.
+#[cfg(feature = "alloc")]
+pub enum CowBitSlice<'de, O>
+where O: OrderTag
+{
+	/// Borrowed directly out of the deserializer's input buffer.
+	Borrowed(&'de BitSlice<u8, O>),
+	/// Decoded into a freshly allocated `BitVec`, because the
+	/// deserializer could not hand back a borrowed byte slice.
+	Owned(BitVec<u8, O>),
+}
+
+#[cfg(feature = "alloc")]
+impl<O> Deref for CowBitSlice<'_, O>
+where O: OrderTag
+{
+	type Target = BitSlice<u8, O>;
+
+	fn deref(&self) -> &Self::Target {
+		match self {
+			Self::Borrowed(slice) => slice,
+			Self::Owned(vec) => vec.as_bitslice(),
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, O> Deserialize<'de> for CowBitSlice<'de, O>
+where O: OrderTag
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct(
+			"BitSeq",
+			FIELDS,
+			BitSeqVisitor::<'de, u8, O, CowBytesVisitor, Self, _>::new(
+				|bytes, head, bits| match bytes {
+					CowBytes::Borrowed(data) => unsafe {
+						BitSpan::new(data.as_ptr().into_address(), head, bits)
+							.map(|span| {
+								Self::Borrowed(BitSpan::into_bitslice_ref(
+									span,
+								))
+							})
+					},
+					CowBytes::Owned(data) => unsafe {
+						let addr = data.as_ptr().into_address();
+						let mut bv =
+							BitVec::try_from_vec(data).map_err(|_| {
+								BitSpan::<Const, u8, O>::new(addr, head, bits)
+									.unwrap_err()
+							})?;
+						bv.set_head(head);
+						bv.set_len(bits);
+						Ok(Self::Owned(bv))
+					},
+				},
+			),
+		)
+	}
+}
+
+/// The `data` field, deserialized as either a borrowed or an owned byte
+/// buffer depending on what the format is able to provide.
+#[cfg(feature = "alloc")]
+enum CowBytes<'de> {
+	Borrowed(&'de [u8]),
+	Owned(Vec<u8>),
+}
+
+#[cfg(feature = "alloc")]
+struct CowBytesVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> DeserializeSeed<'de> for CowBytesVisitor {
+	type Value = CowBytes<'de>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_bytes(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> BitDataSeed<'de, u8> for CowBytesVisitor {
+	fn new(_head: Option<BitIdx<u8>>, _bits: Option<usize>) -> Self {
+		Self
+	}
+
+	fn bit_len(value: &Self::Value) -> usize {
+		let len = match value {
+			CowBytes::Borrowed(bytes) => bytes.len(),
+			CowBytes::Owned(bytes) => bytes.len(),
+		};
+		len * bits_of::<u8>()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for CowBytesVisitor {
+	type Value = CowBytes<'de>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a byte blob or sequence of bytes")
+	}
+
+	fn visit_borrowed_bytes<E>(self, bytes: &'de [u8]) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(CowBytes::Borrowed(bytes))
+	}
+
+	fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(CowBytes::Owned(bytes.to_vec()))
+	}
+
+	fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(CowBytes::Owned(bytes))
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(elem) = seq.next_element::<u8>()? {
+			out.push(elem);
+		}
+		Ok(CowBytes::Owned(out))
+	}
+}
+
 #[cfg(feature = "alloc")]
 impl<'de, T, O> Deserialize<'de> for BitBox<T, O>
 where
 	T: BitStore,
-	O: BitOrder,
-	Vec<T>: Deserialize<'de>,
+	O: OrderTag,
+	T: Deserialize<'de>,
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
@@ -131,15 +461,15 @@ where
 impl<'de, T, O> Deserialize<'de> for BitVec<T, O>
 where
 	T: BitStore,
-	O: BitOrder,
-	Vec<T>: Deserialize<'de>,
+	O: OrderTag,
+	T: Deserialize<'de>,
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
 		deserializer.deserialize_struct(
 			"BitSeq",
 			FIELDS,
-			BitSeqVisitor::<'de, T, O, Vec<T>, Self, _>::new(
+			BitSeqVisitor::<'de, T, O, VecDataSeed<T>, Self, _>::new(
 				|vec, head, bits| unsafe {
 					let addr = vec.as_ptr().into_address();
 					let mut bv = BitVec::try_from_vec(vec).map_err(|_| {
@@ -155,13 +485,146 @@ where
 	}
 }
 
+/// Decodes the `data` field into a `Vec<T>`, preallocated to the exact
+/// element count implied by `head` and `bits` when those are already
+/// known, rather than growing an intermediate buffer to an approximate
+/// capacity and copying it into place afterwards.
+///
+/// `head`/`bits` are only known once those fields have actually been
+/// deserialized; in a self-describing map whose key order isn't
+/// insertion order (`serde_json::Value`'s map is one example), a `data`
+/// key can be encountered before them. Rather than erroring out on that
+/// ordering, this just decodes `data` without an exact-count check and
+/// leaves `BitSeqVisitor::assemble` to validate the resulting buffer
+/// against `head`/`bits` once both are known.
+#[cfg(feature = "alloc")]
+struct VecDataSeed<T>
+where T: BitStore
+{
+	head: Option<BitIdx<T::Mem>>,
+	bits: Option<usize>,
+	_mem: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for VecDataSeed<T>
+where T: BitStore + Deserialize<'de>
+{
+	type Value = Vec<T>;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		let elems = match (self.head, self.bits) {
+			(Some(head), Some(bits)) => {
+				let width = bits_of::<T::Mem>();
+				let elems = (head.into_inner() as usize)
+					.checked_add(bits)
+					.and_then(|sum| sum.checked_add(width - 1))
+					.map(|sum| sum / width)
+					.ok_or_else(|| {
+						D::Error::custom(
+							"`bits` overflows the addressable element \
+							 count",
+						)
+					})?;
+				Some(elems)
+			},
+			_ => None,
+		};
+		deserializer.deserialize_bytes(VecDataVisitor::<T> {
+			elems,
+			_mem: PhantomData,
+		})
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> BitDataSeed<'de, T> for VecDataSeed<T>
+where T: BitStore + Deserialize<'de>
+{
+	fn new(head: Option<BitIdx<T::Mem>>, bits: Option<usize>) -> Self {
+		Self {
+			head,
+			bits,
+			_mem: PhantomData,
+		}
+	}
+
+	fn bit_len(value: &Self::Value) -> usize {
+		value.len() * bits_of::<T::Mem>()
+	}
+}
+
+#[cfg(feature = "alloc")]
+struct VecDataVisitor<T> {
+	elems: Option<usize>,
+	_mem:  PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Visitor<'de> for VecDataVisitor<T>
+where T: BitStore + Deserialize<'de>
+{
+	type Value = Vec<T>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self.elems {
+			Some(elems) => write!(
+				fmt,
+				"a byte blob or sequence of {} backing-store elements",
+				elems
+			),
+			None => write!(fmt, "a byte blob or sequence of backing-store elements"),
+		}
+	}
+
+	fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+	where E: Error {
+		let width = core::mem::size_of::<T>();
+		let mismatched = match self.elems {
+			Some(elems) => bytes.len() != elems * width,
+			None => bytes.len() % width != 0,
+		};
+		if mismatched {
+			return Err(E::invalid_length(bytes.len(), &self));
+		}
+		unpack_le_bytes(bytes)
+	}
+
+	fn visit_borrowed_bytes<E>(self, bytes: &'de [u8]) -> Result<Self::Value, E>
+	where E: Error {
+		self.visit_bytes(bytes)
+	}
+
+	fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Self::Value, E>
+	where E: Error {
+		self.visit_bytes(&bytes)
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where A: SeqAccess<'de> {
+		let mut out = Vec::with_capacity(
+			self.elems.unwrap_or_else(|| seq.size_hint().unwrap_or(0)),
+		);
+		while let Some(elem) = seq.next_element::<T>()? {
+			out.push(elem);
+		}
+		if let Some(elems) = self.elems {
+			if out.len() != elems {
+				return Err(A::Error::invalid_length(out.len(), &self));
+			}
+		}
+		Ok(out)
+	}
+}
+
 /// Assists in deserialization of a dynamic `BitSeq`.
-struct BitSeqVisitor<'de, T, O, In, Out, Func>
+struct BitSeqVisitor<'de, T, O, Seed, Out, Func>
 where
 	T: 'de + BitStore,
-	O: BitOrder,
-	In: Deserialize<'de>,
-	Func: FnOnce(In, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
+	O: OrderTag,
+	Seed: BitDataSeed<'de, T>,
+	Func: FnOnce(Seed::Value, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
 {
 	/// This produces a bit-slice reference during its work,
 	typ:   PhantomData<&'de BitSlice<T, O>>,
@@ -174,18 +637,19 @@ where
 	/// The deserialized bit-count.
 	bits:  Option<u64>,
 	/// The deserialized data buffer.
-	data:  Option<In>,
+	data:  Option<Seed::Value>,
 	/// A functor responsible for final transformation of the deserialized
 	/// components into the output value.
 	func:  Func,
 }
 
-impl<'de, T, O, In, Out, Func> BitSeqVisitor<'de, T, O, In, Out, Func>
+impl<'de, T, O, Seed, Out, Func> BitSeqVisitor<'de, T, O, Seed, Out, Func>
 where
 	T: 'de + BitStore,
-	O: BitOrder,
-	In: Deserialize<'de>,
-	Func: FnOnce(In, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
+	O: OrderTag,
+	Seed: BitDataSeed<'de, T>,
+	Func: FnOnce(Seed::Value, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
+	BitSpanError<T>: fmt::Debug,
 {
 	/// Creates a new visitor with a given transform functor.
 	fn new(func: Func) -> Self {
@@ -200,6 +664,12 @@ where
 		}
 	}
 
+	/// Builds the seed used to decode the `data` field from whatever of
+	/// `head`/`bits` has been deserialized so far.
+	fn data_seed(&self) -> Seed {
+		Seed::new(self.head, self.bits.map(|bits| bits as usize))
+	}
+
 	/// Attempts to assemble deserialized components into an output value.
 	fn assemble<E>(mut self) -> Result<Out, E>
 	where E: Error {
@@ -209,21 +679,43 @@ where
 		let bits = self.bits.take().ok_or_else(|| E::missing_field("bits"))?;
 		let data = self.data.take().ok_or_else(|| E::missing_field("data"))?;
 
-		let expected_order = any::type_name::<O>();
-		if order != expected_order {
+		let expected_order = order_tag::<O>();
+		let legacy_order = any::type_name::<O>();
+		if order != expected_order && order != legacy_order {
 			return Err(E::invalid_type(Unexpected::Str(&*order), &self));
 		}
-		(self.func)(data, head, bits as usize).map_err(|_| todo!())
+		// Check `head`/`bits` against the data actually decoded before
+		// handing them to `func`, so a `bits` count that runs past the end
+		// of `data` is reported as a descriptive, discriminated error. The
+		// span construction in `func` sees only the address of `data`, not
+		// its length, and would accept such a count.
+		let capacity = Seed::bit_len(&data) as u64;
+		let span_end = (head.into_inner() as u64).checked_add(bits);
+		if span_end.map_or(true, |end| end > capacity) {
+			return Err(E::invalid_value(
+				Unexpected::Unsigned(bits),
+				&"a `bits` count that fits within the decoded `data` buffer",
+			));
+		}
+
+		(self.func)(data, head, bits as usize).map_err(|err| {
+			E::custom(format_args!(
+				"a `head`/`bits` pair describing a valid, properly \
+				 aligned span, but the span was rejected: {:?}",
+				err,
+			))
+		})
 	}
 }
 
-impl<'de, T, O, In, Out, Func> Visitor<'de>
-	for BitSeqVisitor<'de, T, O, In, Out, Func>
+impl<'de, T, O, Seed, Out, Func> Visitor<'de>
+	for BitSeqVisitor<'de, T, O, Seed, Out, Func>
 where
 	T: 'de + BitStore,
-	O: BitOrder,
-	In: Deserialize<'de>,
-	Func: FnOnce(In, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
+	O: OrderTag,
+	Seed: BitDataSeed<'de, T>,
+	Func: FnOnce(Seed::Value, BitIdx<T::Mem>, usize) -> Result<Out, BitSpanError<T>>,
+	BitSpanError<T>: fmt::Debug,
 {
 	type Value = Out;
 
@@ -250,8 +742,9 @@ where
 			seq.next_element()?
 				.ok_or_else(|| <V::Error>::invalid_length(2, &self))?,
 		);
+		let seed = self.data_seed();
 		self.data = Some(
-			seq.next_element()?
+			seq.next_element_seed(seed)?
 				.ok_or_else(|| <V::Error>::invalid_length(3, &self))?,
 		);
 
@@ -278,7 +771,9 @@ where
 					}
 				},
 				"data" => {
-					if self.data.replace(map.next_value()?).is_some() {
+					let seed = self.data_seed();
+					if self.data.replace(map.next_value_seed(seed)?).is_some()
+					{
 						return Err(<V::Error>::duplicate_field("data"));
 					}
 				},
@@ -328,6 +823,32 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn cow_roundtrip() -> Result<(), alloc::boxed::Box<bincode::ErrorKind>> {
+		use super::CowBitSlice;
+
+		let bits = bits![u8, Msb0; 1, 0, 1, 1, 0];
+
+		// A slice-backed bincode deserializer can borrow, so this should
+		// not allocate.
+		let encoded = bincode::serialize(&bits)?;
+		match bincode::deserialize::<CowBitSlice<Msb0>>(&encoded)? {
+			CowBitSlice::Borrowed(slice) => assert_eq!(bits, slice),
+			CowBitSlice::Owned(_) => panic!("expected a borrowed slice"),
+		}
+
+		// JSON has no native byte-blob type, so it falls back to the
+		// owned path.
+		let encoded = serde_json::to_value(&bits).unwrap();
+		match serde_json::from_value::<CowBitSlice<Msb0>>(encoded).unwrap() {
+			CowBitSlice::Owned(vec) => assert_eq!(bits, vec),
+			CowBitSlice::Borrowed(_) => panic!("expected an owned vec"),
+		}
+
+		Ok(())
+	}
+
 	#[test]
 	fn tokens() {
 		let slice = bits![u8, Lsb0; 0, 1, 0, 0, 1];
@@ -337,7 +858,7 @@ mod tests {
 				len:  4,
 			},
 			Token::Str("order"),
-			Token::Str(any::type_name::<Lsb0>()),
+			Token::Str("Lsb0"),
 			Token::Str("head"),
 			Token::Struct {
 				name: "BitIdx",
@@ -351,9 +872,7 @@ mod tests {
 			Token::Str("bits"),
 			Token::U64(5),
 			Token::Str("data"),
-			Token::Seq { len: Some(1) },
-			Token::U8(18),
-			Token::SeqEnd,
+			Token::Bytes(&[18]),
 			Token::StructEnd,
 		];
 		assert_ser_tokens(&slice, tokens);
@@ -361,6 +880,22 @@ mod tests {
 		tokens[11] = Token::U64(4);
 		assert_ser_tokens(&&slice[1 ..], tokens);
 
+		let tokens = &[
+			Token::Seq { len: Some(4) },
+			Token::BorrowedStr("Lsb0"),
+			Token::Seq { len: Some(2) },
+			Token::U8(8),
+			Token::U8(0),
+			Token::SeqEnd,
+			Token::U64(5),
+			Token::BorrowedBytes(&[18]),
+			Token::SeqEnd,
+		];
+		assert_de_tokens(&slice, tokens);
+
+		// Archives encoded with the old `type_name`-based order string must
+		// still decode, so that upgrading this crate doesn't break data
+		// written by an older version.
 		let tokens = &[
 			Token::Seq { len: Some(4) },
 			Token::BorrowedStr(any::type_name::<Lsb0>()),
@@ -375,6 +910,29 @@ mod tests {
 		assert_de_tokens(&slice, tokens);
 	}
 
+	#[test]
+	fn malformed_span_is_reported_as_error() {
+		// `bits` claims far more storage than the single encoded byte can
+		// provide; `assemble` must report that as a descriptive
+		// deserialization error, since the span constructor is given only
+		// the address of `data` and would otherwise accept the count.
+		assert_de_tokens_error::<&BitSlice<u8, Msb0>>(
+			&[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr("Msb0"),
+				Token::Seq { len: Some(2) },
+				Token::U8(8),
+				Token::U8(0),
+				Token::SeqEnd,
+				Token::U64(1000),
+				Token::BorrowedBytes(&[0]),
+				Token::SeqEnd,
+			],
+			"invalid value: integer `1000`, expected a `bits` count that \
+			 fits within the decoded `data` buffer",
+		);
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn errors() {
@@ -475,5 +1033,18 @@ mod tests {
 			],
 			"duplicate field `data`",
 		);
+		assert_de_tokens_error::<BitVec<u8, Msb0>>(
+			&[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr("Msb0"),
+				Token::Seq { len: Some(2) },
+				Token::U8(8),
+				Token::U8(0),
+				Token::SeqEnd,
+				Token::U64(u64::MAX),
+				Token::BorrowedBytes(&[0]),
+			],
+			"`bits` overflows the addressable element count",
+		);
 	}
 }